@@ -0,0 +1,105 @@
+use crate::rules::Finding;
+use crate::ScanResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+/// A stable identifier for one accepted finding: which rule matched, which
+/// file, and a hash of the redacted text rather than the line number, so
+/// unrelated edits elsewhere in the file don't invalidate the baseline entry.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+struct Fingerprint {
+    path: String,
+    rule: String,
+    hash: String,
+}
+
+/// A persisted set of previously-accepted findings. Scans filter these out
+/// so a legacy repo with known, intentional fixtures only reports *new*
+/// secrets going forward.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    findings: HashSet<Fingerprint>,
+}
+
+impl Baseline {
+    /// Load a baseline from `path`. A missing or unparsable file is treated
+    /// as an empty baseline rather than an error, same as `Config::load`.
+    pub fn load(path: &Path) -> Baseline {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Baseline::default();
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            eprintln!("Warning: failed to parse baseline {}: {e}", path.display());
+            Baseline::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap())
+    }
+
+    /// Build a baseline covering every finding currently present, for
+    /// `--update-baseline`.
+    pub fn from_results(results: &[ScanResult]) -> Baseline {
+        let findings = results
+            .iter()
+            .flat_map(|r| r.secrets.iter().map(move |f| fingerprint(&r.path, f)))
+            .collect();
+        Baseline { findings }
+    }
+
+    pub fn contains(&self, path: &str, finding: &Finding) -> bool {
+        self.findings.contains(&fingerprint(path, finding))
+    }
+}
+
+fn fingerprint(path: &str, finding: &Finding) -> Fingerprint {
+    Fingerprint {
+        path: path.to_string(),
+        rule: finding.rule.clone(),
+        hash: hash_masked(&finding.masked),
+    }
+}
+
+fn hash_masked(masked: &str) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    masked.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Finding;
+
+    fn finding(rule: &str, masked: &str) -> Finding {
+        Finding {
+            rule: rule.to_string(),
+            line: 1,
+            column_start: 0,
+            column_end: 0,
+            masked: masked.to_string(),
+        }
+    }
+
+    #[test]
+    fn baseline_contains_recorded_finding() {
+        let results = vec![ScanResult {
+            path: "config.rs".to_string(),
+            status: String::new(),
+            secrets: vec![finding("AWS Access Key", "AKIA****...****WXYZ")],
+        }];
+        let baseline = Baseline::from_results(&results);
+        assert!(baseline.contains("config.rs", &finding("AWS Access Key", "AKIA****...****WXYZ")));
+        assert!(!baseline.contains("config.rs", &finding("AWS Access Key", "AKIA****...****OTHR")));
+        assert!(!baseline.contains("other.rs", &finding("AWS Access Key", "AKIA****...****WXYZ")));
+    }
+
+    #[test]
+    fn missing_baseline_file_is_empty() {
+        let baseline = Baseline::load(Path::new("/nonexistent/path/for/passcan/tests/baseline.json"));
+        assert!(!baseline.contains("any.rs", &finding("AWS Access Key", "AKIA****...****WXYZ")));
+    }
+}
@@ -0,0 +1,90 @@
+use regex::Regex;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Per-repo scanner configuration, loaded from `passcan.toml` at the scan
+/// root (or an explicit `--config` path). Every field is optional so teams
+/// only need to override what they care about.
+#[derive(Debug, Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub rules: Vec<CustomRule>,
+    #[serde(default)]
+    pub disabled_rules: Vec<String>,
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+    pub ignored_dirs: Option<Vec<String>>,
+    pub ignored_extensions: Option<Vec<String>>,
+    pub code_extensions: Option<Vec<String>>,
+}
+
+/// A user-defined detection rule declared in `passcan.toml`.
+#[derive(Debug, Deserialize)]
+pub struct CustomRule {
+    pub name: String,
+    pub pattern: String,
+    pub entropy_threshold: Option<f64>,
+}
+
+impl Config {
+    /// Load `passcan.toml` from `override_path` if given, else `<root>/passcan.toml`.
+    /// Missing or unparsable config falls back to `Config::default()` rather
+    /// than failing the scan.
+    pub fn load(root: &str, override_path: Option<&str>) -> Config {
+        let path: PathBuf = match override_path {
+            Some(p) => PathBuf::from(p),
+            None => Path::new(root).join("passcan.toml"),
+        };
+
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return Config::default();
+        };
+
+        match toml::from_str(&contents) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Warning: failed to parse {}: {e}", path.display());
+                Config::default()
+            }
+        }
+    }
+
+    pub fn is_rule_disabled(&self, name: &str) -> bool {
+        self.disabled_rules.iter().any(|d| d == name)
+    }
+
+    /// Whether a matched string should be suppressed: either an exact literal
+    /// match against the allowlist, or a match against one of its entries
+    /// compiled as a regex.
+    pub fn is_allowlisted(&self, matched: &str) -> bool {
+        self.allowlist.iter().any(|entry| {
+            entry == matched
+                || Regex::new(entry)
+                    .map(|re| re.is_match(matched))
+                    .unwrap_or(false)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_config_falls_back_to_default() {
+        let config = Config::load("/nonexistent/path/for/passcan/tests", None);
+        assert!(config.rules.is_empty());
+        assert!(config.disabled_rules.is_empty());
+    }
+
+    #[test]
+    fn allowlist_matches_literal_and_regex() {
+        let config = Config {
+            allowlist: vec!["EXAMPLE_KEY_123".to_string(), r"^test-.*$".to_string()],
+            ..Config::default()
+        };
+        assert!(config.is_allowlisted("EXAMPLE_KEY_123"));
+        assert!(config.is_allowlisted("test-fixture-token"));
+        assert!(!config.is_allowlisted("sk_live_realsecretvalue"));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::ScanResult;
+use serde::Serialize;
+use serde_json::json;
+
+/// Output format selected via `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Sarif,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Option<Format> {
+        match s {
+            "table" => Some(Format::Table),
+            "json" => Some(Format::Json),
+            "sarif" => Some(Format::Sarif),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonFinding<'a> {
+    path: &'a str,
+    rule: &'a str,
+    line: usize,
+    column_start: usize,
+    column_end: usize,
+    masked: &'a str,
+}
+
+/// Emit every finding as a flat JSON array, for tooling that wants to
+/// post-process results rather than read the table.
+pub fn print_json(results: &[ScanResult]) {
+    let findings: Vec<JsonFinding> = results
+        .iter()
+        .flat_map(|r| {
+            r.secrets.iter().map(move |f| JsonFinding {
+                path: &r.path,
+                rule: &f.rule,
+                line: f.line,
+                column_start: f.column_start,
+                column_end: f.column_end,
+                masked: &f.masked,
+            })
+        })
+        .collect();
+    println!("{}", serde_json::to_string_pretty(&findings).unwrap());
+}
+
+/// Emit a SARIF 2.1.0 document so GitHub code scanning (and other SARIF
+/// consumers) can annotate findings inline on the diff.
+pub fn print_sarif(results: &[ScanResult]) {
+    let sarif_results: Vec<_> = results
+        .iter()
+        .flat_map(|r| {
+            r.secrets.iter().map(move |f| {
+                json!({
+                    "ruleId": f.rule,
+                    "message": { "text": format!("Potential {} detected ({})", f.rule, f.masked) },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": r.path },
+                            "region": {
+                                "startLine": f.line,
+                                "startColumn": f.column_start + 1,
+                                "endColumn": f.column_end + 1,
+                            }
+                        }
+                    }]
+                })
+            })
+        })
+        .collect();
+
+    let sarif = json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "passcan",
+                    "informationUri": crate::GITHUB_LINK,
+                }
+            },
+            "results": sarif_results,
+        }]
+    });
+
+    println!("{}", serde_json::to_string_pretty(&sarif).unwrap());
+}